@@ -0,0 +1,49 @@
+//! symmetric encryption for private notes.
+//!
+//! A note can be published as plain markdown (the CID alone is enough to read it)
+//! or sealed with XChaCha20-Poly1305 under a random key/nonce pair. For the note a
+//! page was opened on, that key/nonce never travels through the `Vault` JSON or
+//! the gateway: it lives in the URL fragment (see `PartialParsedUrl`), which
+//! browsers never send over the wire. Other encrypted notes, reached through
+//! wikilinks or the sidebar, carry their key/nonce in `NoteLink::Encrypted`
+//! instead, i.e. in the `Vault` JSON itself — readable by anyone who can fetch
+//! the vault, so this only protects the root/permalinked note, not the whole vault.
+
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine as _};
+use chacha20poly1305::{
+    aead::{Aead, KeyInit},
+    XChaCha20Poly1305, XNonce,
+};
+
+/// a key/nonce pair used to decrypt one note.
+#[derive(Debug, Clone)]
+pub struct EncryptionKey {
+    pub key: [u8; 32],
+    pub nonce: [u8; 24],
+}
+
+impl EncryptionKey {
+    /// parses a key and a nonce from their base64 (url-safe, no padding) representation,
+    /// as found in the URL fragment or in a `Vault`'s `ipfsmap` entry.
+    pub fn from_base64(key: &str, nonce: &str) -> Option<Self> {
+        let key: [u8; 32] = URL_SAFE_NO_PAD.decode(key).ok()?.try_into().ok()?;
+        let nonce: [u8; 24] = URL_SAFE_NO_PAD.decode(nonce).ok()?.try_into().ok()?;
+        Some(EncryptionKey { key, nonce })
+    }
+
+    pub fn to_base64(&self) -> (String, String) {
+        (
+            URL_SAFE_NO_PAD.encode(self.key),
+            URL_SAFE_NO_PAD.encode(self.nonce),
+        )
+    }
+}
+
+/// decrypts `ciphertext` with the given key, returning `None` if authentication fails
+/// (wrong key, corrupted content, or truncated ciphertext).
+pub fn decrypt(ciphertext: &[u8], key: &EncryptionKey) -> Option<String> {
+    let cipher = XChaCha20Poly1305::new_from_slice(&key.key).ok()?;
+    let nonce = XNonce::from_slice(&key.nonce);
+    let plaintext = cipher.decrypt(nonce, ciphertext).ok()?;
+    String::from_utf8(plaintext).ok()
+}
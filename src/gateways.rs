@@ -0,0 +1,57 @@
+//! fetching note content from a list of candidate IPFS gateways instead of a
+//! single hardcoded one. A request races the first few gateways and returns
+//! whichever answers first with a 2xx, so one slow or down gateway doesn't
+//! stall (or break) the whole app.
+
+use crate::IpfsHash;
+use gloo_net::http::Request;
+
+/// gateways tried when a vault doesn't override the list. The local node is
+/// listed first, so it is always among the `RACE_COUNT` gateways raced; the
+/// public gateways in that race are queried at the same time.
+pub const DEFAULT_GATEWAYS: &[&str] = &[
+    "http://127.0.0.1:8080/ipfs/",
+    "https://ipfs.io/ipfs/",
+    "https://cloudflare-ipfs.com/ipfs/",
+    "https://dweb.link/ipfs/",
+];
+
+/// how many gateways to race concurrently for a single request.
+pub const RACE_COUNT: usize = 3;
+
+pub fn default_gateways() -> Vec<String> {
+    DEFAULT_GATEWAYS.iter().map(|s| s.to_string()).collect()
+}
+
+fn gateway_url(gateway: &str, hash: &IpfsHash) -> String {
+    format!("{gateway}{}", hash.0)
+}
+
+/// races the first `RACE_COUNT` entries of `gateways` for `hash`, returning the
+/// bytes of whichever gateway answers first with a 2xx. On total failure,
+/// returns the list of gateways that were tried.
+pub async fn fetch_bytes(hash: &IpfsHash, gateways: &[String]) -> Result<Vec<u8>, Vec<String>> {
+    let candidates: Vec<String> = gateways.iter().take(RACE_COUNT).cloned().collect();
+
+    // a vault can set `"gateways": []`; `select_ok` panics on an empty iterator,
+    // so bail out the same way as a total failure instead of reaching it.
+    if candidates.is_empty() {
+        return Err(candidates);
+    }
+
+    let attempts = candidates.iter().map(|gateway| {
+        let url = gateway_url(gateway, hash);
+        Box::pin(async move {
+            let response = Request::get(&url).send().await.map_err(|_| ())?;
+            if !response.ok() {
+                return Err(());
+            }
+            response.binary().await.map_err(|_| ())
+        })
+    });
+
+    match futures::future::select_ok(attempts).await {
+        Ok((bytes, _still_racing)) => Ok(bytes),
+        Err(()) => Err(candidates),
+    }
+}
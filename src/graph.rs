@@ -0,0 +1,53 @@
+//! the vault's wikilink graph: who links to whom, computed once per vault so
+//! the sidebar can show "linked from" backlinks for the note being read.
+
+use std::collections::HashMap;
+
+use crate::wikilink::render_with_wikilinks;
+use crate::{try_fetch_note_content, NoteLink};
+
+/// `graph[name]` lists the notes that `name` links to.
+pub type Graph = HashMap<String, Vec<String>>;
+
+/// fetches and parses every note in `ipfsmap` once, building its outgoing
+/// links graph. A note that fails to fetch or decrypt is treated as having no
+/// outgoing links, rather than aborting the whole computation.
+pub async fn build(ipfsmap: HashMap<String, NoteLink>, gateways: Vec<String>) -> Graph {
+    let fetches = ipfsmap.iter().map(|(name, note_link)| {
+        let name = name.clone();
+        let hash = note_link.hash().clone();
+        let encryption = note_link.encryption();
+        let gateways = gateways.clone();
+        async move {
+            let content = try_fetch_note_content(&hash, encryption, &gateways).await;
+            (name, content)
+        }
+    });
+
+    futures::future::join_all(fetches)
+        .await
+        .into_iter()
+        .map(|(name, content)| {
+            let outgoing = match content {
+                Some(content) => render_with_wikilinks(&content, &ipfsmap)
+                    .1
+                    .into_iter()
+                    .map(|wikilink| wikilink.target)
+                    .collect(),
+                None => Vec::new(),
+            };
+            (name, outgoing)
+        })
+        .collect()
+}
+
+/// inverts an outgoing-links graph into an incoming-links ("backlinks") graph.
+pub fn invert(graph: &Graph) -> Graph {
+    let mut incoming: Graph = HashMap::new();
+    for (from, targets) in graph {
+        for target in targets {
+            incoming.entry(target.clone()).or_default().push(from.clone());
+        }
+    }
+    incoming
+}
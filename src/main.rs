@@ -1,13 +1,32 @@
 use yew::prelude::*;
-use markdown;
-use std::{collections::HashMap, result};
+use std::{
+    cell::RefCell,
+    collections::{HashMap, HashSet, VecDeque},
+    rc::Rc,
+    result,
+};
 use serde::Deserialize;
 use gloo_net::http::Request;
 use gloo::events::EventListener;
 use wasm_bindgen::{JsCast, UnwrapThrowExt};
-use web_sys::{Event, HtmlInputElement, HtmlElement, HtmlButtonElement, InputEvent};
-use regex::Regex;
-use log::info;
+use web_sys::{Event, HtmlInputElement, HtmlElement, InputEvent};
+use yew_router::prelude::*;
+
+mod crypto;
+mod gateways;
+mod graph;
+mod route;
+mod url;
+mod wikilink;
+
+use crypto::EncryptionKey;
+use route::Route;
+use url::PartialParsedUrl;
+use wikilink::{render_with_wikilinks, WIKILINK_SCHEME};
+
+/// max number of prefetch requests running at the same time, so a note with
+/// many wikilinks doesn't flood the gateway with dozens of simultaneous requests.
+const PREFETCH_CONCURRENCY: usize = 4;
 
 #[derive(Debug, Clone)]
 struct IpfsHash(String);
@@ -21,12 +40,71 @@ impl<'de> Deserialize<'de> for IpfsHash {
         }
 }
 
+/// one entry of `Vault::ipfsmap`: a CID, plus the key/nonce to decrypt it if the
+/// note was sealed before being published. A vault can freely mix plain entries
+/// (just a CID) and encrypted ones.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+enum NoteLink {
+    Plain(IpfsHash),
+    Encrypted { hash: IpfsHash, key: String, nonce: String },
+}
+
+impl NoteLink {
+    fn hash(&self) -> &IpfsHash {
+        match self {
+            NoteLink::Plain(hash) => hash,
+            NoteLink::Encrypted { hash, .. } => hash,
+        }
+    }
+
+    fn encryption(&self) -> Option<EncryptionKey> {
+        match self {
+            NoteLink::Plain(_) => None,
+            NoteLink::Encrypted { key, nonce, .. } => EncryptionKey::from_base64(key, nonce),
+        }
+    }
+}
+
 
 struct App {
     vault: Option<Vault>,
     markdown_view: NodeRef,
     link_listeners: Vec<EventListener>,
-    status: Status
+    status: Status,
+    /// key/nonce carried in the URL fragment, used to decrypt the note this
+    /// page was loaded on (the root note, or the permalinked note from `Route::Note`).
+    /// Never read from the `Vault` JSON, so a gateway never sees it. This only
+    /// covers that one note: every other encrypted note reachable via wikilinks
+    /// or the sidebar carries its key/nonce in `NoteLink::Encrypted`, i.e. in the
+    /// `Vault` JSON, which anyone who can fetch the vault can read.
+    root_encryption: Option<EncryptionKey>,
+    /// url of the vault currently loaded, kept around to build `Route`s when
+    /// navigating to another note of the same vault.
+    vault_url: Option<String>,
+    /// cid to jump to once the vault arrives, when the page was opened on a
+    /// `Route::Note` permalink rather than on the vault's root note.
+    pending_note: Option<IpfsHash>,
+    /// note content already downloaded, keyed by cid, so revisiting a note
+    /// through a wikilink is instant and never re-hits the gateway.
+    cache: HashMap<String, String>,
+    /// cids currently being prefetched, so two wikilinks to the same note
+    /// don't queue the same request twice.
+    in_flight: HashSet<String>,
+    /// gateways raced for every note fetch. Defaults to `gateways::DEFAULT_GATEWAYS`,
+    /// but a vault can override it to pin readers to a preferred gateway.
+    gateways: Vec<String>,
+    /// name (the `ipfsmap` key) of the note currently being read, used to
+    /// highlight it in the sidebar and to look up its backlinks.
+    current_note_name: Option<String>,
+    /// the vault's outgoing-links graph, fetched and parsed lazily the first
+    /// time a vault is loaded. `graph::invert` turns it into backlinks.
+    graph: Option<graph::Graph>,
+}
+
+#[derive(Properties, PartialEq, Clone)]
+struct AppProps {
+    route: Route,
 }
 
 enum Status {
@@ -34,7 +112,9 @@ enum Status {
     Error,
     WaitingForFile(String),
     WaitingForVault(String),
-    Reading(String)
+    Reading(String),
+    DecryptionFailed,
+    GatewayFailure { cid: String, tried: Vec<String> },
 }
 
 
@@ -46,35 +126,57 @@ enum Status {
 struct Vault {
     root: String,
     author: String,
-    // TODO: date 
+    // TODO: date
     // https://stackoverflow.com/questions/67803619/using-serdeserialize-with-optionchronodatetime
-    ipfsmap: HashMap<String, IpfsHash>
+    ipfsmap: HashMap<String, NoteLink>,
+    /// overrides `gateways::DEFAULT_GATEWAYS` when set, so a publisher can pin
+    /// readers to a preferred gateway (e.g. their own node).
+    #[serde(default)]
+    gateways: Option<Vec<String>>,
 }
 
 enum Msg {
     FetchVault,
     ReceiveVault(Vault),
-    FetchNote(IpfsHash),
+    FetchNote(IpfsHash, Option<EncryptionKey>),
     ReceiveNote(String),
+    /// a prefetch finished: `None` means every gateway failed or decryption failed.
+    NotePrefetched(String, Option<String>),
+    DecryptionFailed,
+    /// every gateway raced for a cid failed; carries the cid and the gateways tried.
+    FetchFailed(String, Vec<String>),
     SetUrl(String),
+    GraphBuilt(graph::Graph),
 }
 
-/// request a file using its cid on ipfs.io
-/// If the client has installed [ipfs](ipfs.io), it will not use the gateway
-fn ipfs_request(h: &IpfsHash) -> Request {
-    Request::get(&format!("https://ipfs.io/ipfs/{}", h.0))
+async fn build_graph(ipfsmap: HashMap<String, NoteLink>, gateways: Vec<String>) -> Msg {
+    Msg::GraphBuilt(graph::build(ipfsmap, gateways).await)
 }
 
-async fn fetch_note_content_and_read(hash: IpfsHash) -> Msg {
-    let content = ipfs_request(&hash)
-        .send()
-        .await
-        .expect("la requette ipfs a échoué")
-        .text()
-        .await
-        .expect("contenu du fichier invalide");
+async fn fetch_note_content_and_read(hash: IpfsHash, encryption: Option<EncryptionKey>, gateways: Vec<String>) -> Msg {
+    let bytes = match gateways::fetch_bytes(&hash, &gateways).await {
+        Ok(bytes) => bytes,
+        Err(tried) => return Msg::FetchFailed(hash.0, tried),
+    };
+
+    match encryption {
+        Some(key) => match crypto::decrypt(&bytes, &key) {
+            Some(content) => Msg::ReceiveNote(content),
+            None => Msg::DecryptionFailed,
+        },
+        None => Msg::ReceiveNote(String::from_utf8(bytes).expect("contenu du fichier invalide")),
+    }
+}
 
-    Msg::ReceiveNote(content)
+/// like `fetch_note_content_and_read`, but used for prefetching: a failed
+/// request or a failed decryption is simply reported as `None` instead of
+/// going through the `Status`-changing paths of the visible fetch.
+async fn try_fetch_note_content(hash: &IpfsHash, encryption: Option<EncryptionKey>, gateways: &[String]) -> Option<String> {
+    let bytes = gateways::fetch_bytes(hash, gateways).await.ok()?;
+    match encryption {
+        Some(key) => crypto::decrypt(&bytes, &key),
+        None => String::from_utf8(bytes).ok(),
+    }
 }
 
 async fn fetch_vault_description_and_start(url: String) -> Msg {
@@ -91,55 +193,45 @@ async fn fetch_vault_description_and_start(url: String) -> Msg {
 
 
 
-/// `extract_link(wikilink, associations)` extracts from `wikilink` of the form `link|text` 
-/// a couple `(text, ipfs_link)` where
-/// - `text` is the textual part of the link
-/// - `ipfs_link` is the hash associated to the address part of the link
-fn extract_link(wikilink: &str, associations: &HashMap<String, IpfsHash>) -> (String, Option<IpfsHash>)  {
-    let parts_of_link : Vec<&str> = wikilink.split("|").collect();
-    if parts_of_link.len() == 2 {
-        (parts_of_link[1].to_string(), associations.get(parts_of_link[0]).map(|x| x.clone()))
-    }
-    else {
-        info!("{}", parts_of_link[0]);
-        (parts_of_link[0].to_string(), associations.get(parts_of_link[0]).map(|x| x.clone()))
-    }
-}
-
-/// `set_markdown_content(content, associations, html_element, ctx)` change the element 
+/// `set_markdown_content(content, associations, html_element, ctx)` change the element
 /// of the node `html_element` with the html representation of the markdown `content`.
-/// It also converts all the \[\[wikilinks\]\] from the markdown to clickable buttons,
+/// It also converts all the \[\[wikilinks\]\] from the markdown to clickable links,
 /// using `associations` to create the ipfs links.
-/// It will return a list of `EventListener` corresponding to the button click-events
-fn set_markdown_content(content: &str, associations: &HashMap<String, IpfsHash>, 
-                        html_element: &HtmlElement, ctx: &Context<App>, listeners: &mut Vec<EventListener>) {
-    // TODO: styling. https://stackoverflow.com/questions/1367409/how-to-make-button-look-like-a-link
-
-    let raw_html = format!("<div style=\"border: 2px solid red\">{}</div>", markdown::to_html(content));
-    let re = Regex::new(r"\[\[(.*?)\]\]").unwrap();
-    let html_with_link_converted = re.replace_all(&raw_html, "<button></button>").to_string();
-    let link_matches : Vec<_> = re.captures_iter(&raw_html).collect();
-
-    html_element.set_inner_html(&html_with_link_converted);
-
-    let links = html_element.query_selector_all("button").unwrap();
+/// Fills `listeners` with the `EventListener`s corresponding to the link click-events,
+/// and returns every resolved wikilink target, so the caller can prefetch them.
+fn set_markdown_content(content: &str, associations: &HashMap<String, NoteLink>,
+                        html_element: &HtmlElement, ctx: &Context<App>, listeners: &mut Vec<EventListener>) -> Vec<NoteLink> {
+    let (html, wikilinks) = render_with_wikilinks(content, associations);
+    html_element.set_inner_html(&html);
+
+    let selector = format!("a[href^=\"{WIKILINK_SCHEME}\"]");
+    let anchors = html_element.query_selector_all(&selector).unwrap();
     listeners.clear();
-    for i in 0..link_matches.len() {
-        let button: HtmlButtonElement = links.get(i as u32).unwrap().dyn_into().unwrap();
-        let link_text = &link_matches[i][1];
-        let (name, hash) = extract_link(&link_text, associations);
-        button.set_inner_text(&name);
-        if let Some(real_hash) = hash {
+    let mut resolved = Vec::new();
+    for (i, wikilink) in wikilinks.into_iter().enumerate() {
+        let anchor: HtmlElement = anchors.get(i as u32).unwrap().dyn_into().unwrap();
+        match wikilink.link {
             // lien disponible
-            let callback = ctx.link().callback(move |()| Msg::FetchNote(real_hash.clone()));
-            let event_listener = EventListener::new(&button, "click", move |_| callback.emit(()));
-            listeners.push(event_listener);
-        }
-        else {
+            Some(note_link) => {
+                let hash = note_link.hash().clone();
+                let encryption = note_link.encryption();
+                let callback = ctx.link().callback(move |()| Msg::FetchNote(hash.clone(), encryption.clone()));
+                let event_listener = EventListener::new(&anchor, "click", move |event| {
+                    event.prevent_default();
+                    callback.emit(());
+                });
+                listeners.push(event_listener);
+                resolved.push(note_link);
+            }
             // lien non disponible
-            button.style().set_property("background-color", "red").unwrap()
+            None => {
+                anchor.set_attribute("style", "color: red").unwrap();
+                let event_listener = EventListener::new(&anchor, "click", |event| event.prevent_default());
+                listeners.push(event_listener);
+            }
         }
     }
+    resolved
 }
 
 fn get_value_from_input_event(e: InputEvent) -> String {
@@ -159,18 +251,127 @@ fn url_input(url: &str, ctx: &Context<App>) -> Html {
     }
 }
 
+/// builds the initial state for a freshly (re)created `App`, by parsing the
+/// route the page was loaded on and kicking off the matching fetch: nothing
+/// for `Route::Index`, the vault for `Route::Vault`, and the vault followed
+/// by a jump to `cid` for `Route::Note`. A pasted deep link lands directly
+/// on the right note instead of the "enter url" home screen.
+fn start_from_route(route: &Route, ctx: &Context<App>) -> App {
+    let root_encryption = web_sys::window()
+        .and_then(|w| w.location().hash().ok())
+        .and_then(|hash| PartialParsedUrl::parse(&hash))
+        .and_then(|parsed| parsed.into_encryption_key());
+
+    let vault_url = route.vault_url();
+    let pending_note = route.cid().map(|cid| IpfsHash(cid.to_string()));
+
+    let status = match (&route, &vault_url) {
+        (Route::Index, _) => Status::Home("enter url".to_string()),
+        (_, Some(url)) => {
+            ctx.link().send_future(fetch_vault_description_and_start(url.clone()));
+            Status::WaitingForVault(url.clone())
+        }
+        (_, None) => Status::Error,
+    };
+
+    App {
+        status,
+        vault: None,
+        markdown_view: NodeRef::default(),
+        link_listeners: vec![],
+        root_encryption,
+        vault_url,
+        pending_note,
+        cache: HashMap::new(),
+        in_flight: HashSet::new(),
+        gateways: gateways::default_gateways(),
+        current_note_name: None,
+        graph: None,
+    }
+}
+
+impl App {
+    /// prefetches every wikilink target in `links` that isn't already cached or
+    /// in flight, fetching up to `PREFETCH_CONCURRENCY` notes at a time (each
+    /// fetch races up to `gateways::RACE_COUNT` gateways).
+    fn prefetch_links(&mut self, links: Vec<NoteLink>, ctx: &Context<Self>) {
+        let mut queue = VecDeque::new();
+        for link in links {
+            let hash = link.hash().clone();
+            if self.cache.contains_key(&hash.0) || self.in_flight.contains(&hash.0) {
+                continue;
+            }
+            self.in_flight.insert(hash.0.clone());
+            queue.push_back((hash, link.encryption()));
+        }
+        if queue.is_empty() {
+            return;
+        }
+
+        let queue = Rc::new(RefCell::new(queue));
+        for _ in 0..PREFETCH_CONCURRENCY {
+            let queue = queue.clone();
+            let link = ctx.link().clone();
+            let gateways = self.gateways.clone();
+            wasm_bindgen_futures::spawn_local(async move {
+                loop {
+                    let next = queue.borrow_mut().pop_front();
+                    let Some((hash, encryption)) = next else { break };
+                    let content = try_fetch_note_content(&hash, encryption, &gateways).await;
+                    link.send_message(Msg::NotePrefetched(hash.0, content));
+                }
+            });
+        }
+    }
+
+    /// the persistent vault navigation sidebar: every note name (sorted),
+    /// the current one highlighted, and a "linked from" backlinks panel for it.
+    fn sidebar(&self, ctx: &Context<Self>) -> Html {
+        let Some(vault) = &self.vault else { return html! {} };
+
+        let mut names: Vec<&String> = vault.ipfsmap.keys().collect();
+        names.sort();
+
+        let backlinks = self.graph.as_ref().map(graph::invert);
+        let incoming: Vec<String> = self.current_note_name.as_ref()
+            .and_then(|name| backlinks.as_ref().and_then(|b| b.get(name)))
+            .cloned()
+            .unwrap_or_default();
+
+        html! {
+            <nav style="border-right: 1px solid black; padding: 0.5em; min-width: 12em;">
+                <h3>{"notes"}</h3>
+                <ul>
+                { for names.into_iter().map(|name| {
+                    let note_link = vault.ipfsmap.get(name).unwrap().clone();
+                    let hash = note_link.hash().clone();
+                    let encryption = note_link.encryption();
+                    let is_current = self.current_note_name.as_deref() == Some(name.as_str());
+                    let onclick = ctx.link().callback(move |_| Msg::FetchNote(hash.clone(), encryption.clone()));
+                    let style = if is_current { "font-weight: bold" } else { "font-weight: normal" };
+                    html! {
+                        <li>
+                            <button style={style} onclick={onclick}>{name.clone()}</button>
+                        </li>
+                    }
+                }) }
+                </ul>
+                <h3>{"linked from"}</h3>
+                <ul>
+                { for incoming.iter().map(|name| html! { <li>{name.clone()}</li> }) }
+                </ul>
+            </nav>
+        }
+    }
+}
+
 impl Component for App {
     type Message = Msg;
 
-    type Properties = ();
+    type Properties = AppProps;
 
-    fn create(_ctx: &Context<Self>) -> Self {
-        App {
-            status : Status::Home("enter url".to_string()),
-            vault: None,
-            markdown_view: NodeRef::default(),
-            link_listeners: vec![],
-        }
+    fn create(ctx: &Context<Self>) -> Self {
+        start_from_route(&ctx.props().route, ctx)
     }
 
     fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
@@ -179,29 +380,73 @@ impl Component for App {
             Msg::FetchVault => {
                 match &self.status {
                     Status::Home(url) => {
+                        self.vault_url = Some(url.clone());
                         ctx.link().send_future(fetch_vault_description_and_start(url.clone()));
                     },
                     _ => panic!()
                 }
             }
             Msg::ReceiveVault(vault) => {
-                let root_note_name = vault.root.clone();
-                let hash = vault.ipfsmap.get(&root_note_name).unwrap().clone();
-                ctx.link().send_future(fetch_note_content_and_read(hash));
+                let (name, note_link) = match &self.pending_note {
+                    Some(cid) => vault.ipfsmap.iter()
+                        .find(|(_, link)| link.hash().0 == cid.0)
+                        .map(|(name, link)| (name.clone(), link.clone()))
+                        .unwrap_or_else(|| (cid.0.clone(), NoteLink::Plain(cid.clone()))),
+                    None => (vault.root.clone(), vault.ipfsmap.get(&vault.root).unwrap().clone()),
+                };
+                let hash = note_link.hash().clone();
+                let encryption = self.root_encryption.clone().or_else(|| note_link.encryption());
+                self.gateways = vault.gateways.clone().unwrap_or_else(gateways::default_gateways);
+                self.current_note_name = Some(name);
+                ctx.link().send_future(fetch_note_content_and_read(hash.clone(), encryption, self.gateways.clone()));
+                ctx.link().send_future(build_graph(vault.ipfsmap.clone(), self.gateways.clone()));
                 self.status = Status::WaitingForFile(vault.root.clone());
+                if let (Some(navigator), Some(vault_url)) = (ctx.link().navigator(), &self.vault_url) {
+                    match &self.pending_note {
+                        // a permalink to a specific note: keep it in the url so a
+                        // refresh lands back on the same note instead of the root.
+                        Some(_) => navigator.push(&Route::note(vault_url, &hash.0)),
+                        None => navigator.push(&Route::vault(vault_url)),
+                    }
+                }
                 self.vault = Some(vault);
             }
             Msg::ReceiveNote(content) => {
-                set_markdown_content(&content, 
-                                     &self.vault.as_ref().unwrap().ipfsmap, 
-                                     &self.markdown_view.cast::<HtmlElement>().unwrap(), 
+                let wikilinks = set_markdown_content(&content,
+                                     &self.vault.as_ref().unwrap().ipfsmap,
+                                     &self.markdown_view.cast::<HtmlElement>().unwrap(),
                                      ctx,
                                      &mut self.link_listeners
                 );
+                self.prefetch_links(wikilinks, ctx);
                 self.status = Status::Reading(content);
             }
-            Msg::FetchNote(hash) => {
-                ctx.link().send_future(fetch_note_content_and_read(hash));
+            Msg::FetchNote(hash, encryption) => {
+                if let (Some(navigator), Some(vault_url)) = (ctx.link().navigator(), &self.vault_url) {
+                    navigator.push(&Route::note(vault_url, &hash.0));
+                }
+                self.current_note_name = self.vault.as_ref().and_then(|vault| {
+                    vault.ipfsmap.iter().find(|(_, link)| link.hash().0 == hash.0).map(|(name, _)| name.clone())
+                });
+                match self.cache.get(&hash.0) {
+                    Some(content) => ctx.link().send_message(Msg::ReceiveNote(content.clone())),
+                    None => ctx.link().send_future(fetch_note_content_and_read(hash, encryption, self.gateways.clone())),
+                }
+            }
+            Msg::NotePrefetched(cid, content) => {
+                self.in_flight.remove(&cid);
+                if let Some(content) = content {
+                    self.cache.insert(cid, content);
+                }
+            }
+            Msg::DecryptionFailed => {
+                self.status = Status::DecryptionFailed;
+            }
+            Msg::FetchFailed(cid, tried) => {
+                self.status = Status::GatewayFailure { cid, tried };
+            }
+            Msg::GraphBuilt(graph) => {
+                self.graph = Some(graph);
             }
         }
         true
@@ -220,22 +465,68 @@ impl Component for App {
             Status::WaitingForFile(_) => html!{
                 <p>{"the note is coming ..."}</p>
             },
-            Status::WaitingForVault(_) => todo!(),
+            Status::WaitingForVault(url) => html!{
+                <p>{format!("loading vault from {url} ...")}</p>
+            },
             Status::Reading(s) => html! {
                 "reading ..."
-            }
+            },
+            Status::DecryptionFailed => html!{<h1> {"this note could not be decrypted"} </h1>},
+            Status::GatewayFailure { cid, tried } => html!{
+                <h1> {format!("could not fetch {cid}: every gateway failed ({})", tried.join(", "))} </h1>
+            },
         };
         html! {
-            <>
-            {page} 
-            <div style="border: 2px solid red" ref={&self.markdown_view}> </div>
-            <h3>{"debug:"} </h3>
-            <p>{format!("{:?}", self.vault)}</p>
-            </>
+            <div style="display: flex;">
+                { self.sidebar(ctx) }
+                <div style="flex: 1;">
+                    {page}
+                    <div style="border: 2px solid red" ref={&self.markdown_view}> </div>
+                    <h3>{"debug:"} </h3>
+                    <p>{format!("{:?}", self.vault)}</p>
+                </div>
+            </div>
         }
     }
 
-    fn changed(&mut self, ctx: &Context<Self>, _old_props: &Self::Properties) -> bool {
+    fn changed(&mut self, ctx: &Context<Self>, old_props: &Self::Properties) -> bool {
+        let route = &ctx.props().route;
+        if route == &old_props.route {
+            return true;
+        }
+
+        if route.vault_url() != self.vault_url {
+            // a real Back/Forward to another vault, or a deep link freshly opened.
+            *self = start_from_route(route, ctx);
+            return true;
+        }
+
+        // same vault: either Back/Forward between two notes of it, or the route
+        // this component just pushed itself from `Msg::FetchNote`/`Msg::ReceiveVault`
+        // (which set `current_note_name` in that same update, before this runs, so
+        // the two agree and the branch below is a no-op). Only a genuine external
+        // navigation leaves them out of sync, and reloading that note can hit
+        // `self.cache`, keeping chunk0-4's cache and chunk0-6's graph intact.
+        if let Some(vault) = &self.vault {
+            let target_name = match route.cid() {
+                Some(cid) => vault.ipfsmap.iter()
+                    .find(|(_, link)| link.hash().0 == cid)
+                    .map(|(name, _)| name.clone())
+                    .unwrap_or_else(|| cid.to_string()),
+                None => vault.root.clone(),
+            };
+            if Some(&target_name) != self.current_note_name.as_ref() {
+                if let Some(note_link) = vault.ipfsmap.get(&target_name).cloned() {
+                    let hash = note_link.hash().clone();
+                    let encryption = note_link.encryption();
+                    self.current_note_name = Some(target_name);
+                    match self.cache.get(&hash.0) {
+                        Some(content) => ctx.link().send_message(Msg::ReceiveNote(content.clone())),
+                        None => ctx.link().send_future(fetch_note_content_and_read(hash, encryption, self.gateways.clone())),
+                    }
+                }
+            }
+        }
         true
     }
 
@@ -248,7 +539,20 @@ impl Component for App {
     fn destroy(&mut self, ctx: &Context<Self>) {}
 }
 
+fn switch(route: Route) -> Html {
+    html! { <App route={route} /> }
+}
+
+#[function_component(Root)]
+fn root() -> Html {
+    html! {
+        <BrowserRouter>
+            <Switch<Route> render={switch} />
+        </BrowserRouter>
+    }
+}
+
 fn main() {
     wasm_logger::init(wasm_logger::Config::default());
-    yew::Renderer::<App>::new().render();
+    yew::Renderer::<Root>::new().render();
 }
@@ -0,0 +1,51 @@
+//! the three pages of the app, so a note can be linked to directly and the
+//! browser Back/Forward buttons walk the note graph instead of reloading
+//! back to the landing page.
+//!
+//! `url` segments are percent-encoded since a vault url can itself contain
+//! slashes and query parameters, which would otherwise be split across
+//! several path segments.
+
+use yew_router::prelude::*;
+
+#[derive(Clone, Debug, PartialEq, Routable)]
+pub enum Route {
+    #[at("/")]
+    Index,
+    #[at("/vault/:url")]
+    Vault { url: String },
+    #[at("/vault/:url/note/:cid")]
+    Note { url: String, cid: String },
+}
+
+impl Route {
+    pub fn vault(vault_url: &str) -> Self {
+        Route::Vault {
+            url: urlencoding::encode(vault_url).into_owned(),
+        }
+    }
+
+    pub fn note(vault_url: &str, cid: &str) -> Self {
+        Route::Note {
+            url: urlencoding::encode(vault_url).into_owned(),
+            cid: cid.to_string(),
+        }
+    }
+
+    /// the (percent-decoded) vault url this route points at, if any.
+    pub fn vault_url(&self) -> Option<String> {
+        match self {
+            Route::Index => None,
+            Route::Vault { url } | Route::Note { url, .. } => {
+                urlencoding::decode(url).ok().map(|s| s.into_owned())
+            }
+        }
+    }
+
+    pub fn cid(&self) -> Option<&str> {
+        match self {
+            Route::Note { cid, .. } => Some(cid),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,41 @@
+//! parsing of the `key`/`nonce` pair carried in the URL fragment.
+//!
+//! The fragment (the part of the URL after `#`) is never sent to the server,
+//! which makes it the right place to carry the decryption key for the note
+//! currently being read: `#key=<base64>&nonce=<base64>`.
+
+use crate::crypto::EncryptionKey;
+
+/// the pieces of information we can extract from `location.hash` without
+/// needing the rest of the URL.
+pub struct PartialParsedUrl {
+    pub key: String,
+    pub nonce: String,
+}
+
+impl PartialParsedUrl {
+    /// parses a fragment such as `#key=abc&nonce=def` (the leading `#` is optional).
+    pub fn parse(fragment: &str) -> Option<Self> {
+        let fragment = fragment.strip_prefix('#').unwrap_or(fragment);
+
+        let mut key = None;
+        let mut nonce = None;
+        for part in fragment.split('&') {
+            let (name, value) = part.split_once('=')?;
+            match name {
+                "key" => key = Some(value.to_string()),
+                "nonce" => nonce = Some(value.to_string()),
+                _ => {}
+            }
+        }
+
+        Some(PartialParsedUrl {
+            key: key?,
+            nonce: nonce?,
+        })
+    }
+
+    pub fn into_encryption_key(self) -> Option<EncryptionKey> {
+        EncryptionKey::from_base64(&self.key, &self.nonce)
+    }
+}
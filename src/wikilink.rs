@@ -0,0 +1,140 @@
+//! `[[target|label]]` wikilink extraction, done on the markdown `Event` stream
+//! rather than on the rendered HTML. Walking events instead of regexing HTML
+//! means a `[[` inside a code span, a fenced block or an attribute value is
+//! never mistaken for a link.
+
+use pulldown_cmark::{CowStr, Event, LinkType, Parser, Tag, TagEnd, TextMergeStream};
+
+use crate::NoteLink;
+use std::collections::HashMap;
+
+/// scheme used for the synthetic links injected in place of `[[wikilink]]` syntax,
+/// so the html renderer treats them like any other link while we can still
+/// recognize and intercept clicks on them afterwards.
+pub const WIKILINK_SCHEME: &str = "ipfs-wikilink:";
+
+/// a wikilink found while walking the event stream, resolved against the
+/// vault's `ipfsmap`.
+pub struct ResolvedWikilink {
+    pub target: String,
+    pub label: String,
+    pub link: Option<NoteLink>,
+}
+
+/// splits `source` into markdown events, turning every `[[target|label]]`
+/// occurrence into a synthetic link event, and renders the result to HTML.
+/// Returns the rendered HTML together with every wikilink found, in document order.
+pub fn render_with_wikilinks(
+    source: &str,
+    associations: &HashMap<String, NoteLink>,
+) -> (String, Vec<ResolvedWikilink>) {
+    let mut resolved = Vec::new();
+    let mut in_code_block = false;
+    // consecutive text events are merged first: a `[[...]]` in running text can
+    // otherwise arrive split over several text events around its brackets.
+    let events = TextMergeStream::new(Parser::new(source)).flat_map(|event| match event {
+        Event::Start(Tag::CodeBlock(_)) => {
+            in_code_block = true;
+            vec![event]
+        }
+        Event::End(TagEnd::CodeBlock) => {
+            in_code_block = false;
+            vec![event]
+        }
+        Event::Text(text) if !in_code_block => split_wikilinks(&text, associations, &mut resolved),
+        other => vec![other],
+    });
+
+    let mut html_output = String::new();
+    pulldown_cmark::html::push_html(&mut html_output, events.into_iter());
+    (html_output, resolved)
+}
+
+/// splits a text node around `[[target|label]]` occurrences, emitting a
+/// `Tag::Link`/`TagEnd::Link` pair (with our custom scheme) for each one.
+fn split_wikilinks<'a>(
+    text: &str,
+    associations: &HashMap<String, NoteLink>,
+    resolved: &mut Vec<ResolvedWikilink>,
+) -> Vec<Event<'a>> {
+    let mut events = Vec::new();
+    let mut rest = text;
+
+    while let Some(start) = rest.find("[[") {
+        if start > 0 {
+            events.push(Event::Text(CowStr::from(rest[..start].to_string())));
+        }
+        let Some(end) = rest[start..].find("]]") else {
+            events.push(Event::Text(CowStr::from(rest[start..].to_string())));
+            rest = "";
+            break;
+        };
+        let end = start + end;
+        let inner = &rest[start + 2..end];
+
+        let (target, label) = match inner.split_once('|') {
+            Some((target, label)) => (target.to_string(), label.to_string()),
+            None => (inner.to_string(), inner.to_string()),
+        };
+        let link = associations.get(&target).cloned();
+
+        let dest_url = CowStr::from(format!("{WIKILINK_SCHEME}{target}"));
+        events.push(Event::Start(Tag::Link {
+            link_type: LinkType::Inline,
+            dest_url,
+            title: CowStr::from(""),
+            id: CowStr::from(""),
+        }));
+        events.push(Event::Text(CowStr::from(label.clone())));
+        events.push(Event::End(TagEnd::Link));
+
+        resolved.push(ResolvedWikilink { target, label, link });
+
+        rest = &rest[end + 2..];
+    }
+
+    if !rest.is_empty() {
+        events.push(Event::Text(CowStr::from(rest.to_string())));
+    }
+
+    events
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ignores_wikilinks_inside_a_fenced_code_block() {
+        let source = "```\n[[target|label]]\n```\n";
+        let (html, wikilinks) = render_with_wikilinks(source, &HashMap::new());
+
+        assert!(wikilinks.is_empty());
+        assert!(html.contains("[[target|label]]"));
+        assert!(!html.contains(WIKILINK_SCHEME));
+    }
+
+    #[test]
+    fn tolerates_an_unterminated_wikilink() {
+        let source = "see [[target for more";
+        let (html, wikilinks) = render_with_wikilinks(source, &HashMap::new());
+
+        assert!(wikilinks.is_empty());
+        assert!(html.contains("[[target for more"));
+    }
+
+    #[test]
+    fn resolves_a_labeled_wikilink_against_associations() {
+        let mut associations = HashMap::new();
+        associations.insert("target".to_string(), crate::NoteLink::Plain(crate::IpfsHash("cid".to_string())));
+
+        let (html, wikilinks) = render_with_wikilinks("a [[target|label]] link", &associations);
+
+        assert_eq!(wikilinks.len(), 1);
+        assert_eq!(wikilinks[0].target, "target");
+        assert_eq!(wikilinks[0].label, "label");
+        assert!(wikilinks[0].link.is_some());
+        assert!(html.contains(&format!("{WIKILINK_SCHEME}target")));
+        assert!(html.contains("label"));
+    }
+}